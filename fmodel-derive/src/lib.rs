@@ -0,0 +1,89 @@
+//! Derive macros for the `fmodel` marker traits.
+//!
+//! Annotating a domain type with `#[derive(Command)]`, `#[derive(Event)]` or
+//! `#[derive(State)]` implements the corresponding (otherwise hand-written)
+//! marker trait. `#[derive(Event)]` additionally generates an `event_type`
+//! helper on event enums — a string discriminant useful for tagging serialized
+//! events in a store. The marker trait must be in scope at the derive site.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields};
+
+/// Derive an empty `Command` marker impl.
+#[proc_macro_derive(Command)]
+pub fn derive_command(input: TokenStream) -> TokenStream {
+    derive_marker(parse_macro_input!(input as DeriveInput), quote!(Command))
+}
+
+/// Derive a `State` marker impl.
+#[proc_macro_derive(State)]
+pub fn derive_state(input: TokenStream) -> TokenStream {
+    derive_marker(parse_macro_input!(input as DeriveInput), quote!(State))
+}
+
+/// Derive an `Event` marker impl plus an `event_type` discriminant helper.
+#[proc_macro_derive(Event)]
+pub fn derive_event(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let marker = quote! {
+        impl #impl_generics Event for #name #ty_generics #where_clause {}
+    };
+
+    let event_type = event_type_method(&input);
+
+    let expanded = quote! {
+        #marker
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// The variant name (the type name for a non-enum type) as a
+            /// string, for tagging serialized events.
+            pub fn event_type(&self) -> &'static str {
+                #event_type
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+fn derive_marker(input: DeriveInput, trait_name: proc_macro2::TokenStream) -> TokenStream {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let expanded = quote! {
+        impl #impl_generics #trait_name for #name #ty_generics #where_clause {}
+    };
+
+    expanded.into()
+}
+
+/// Build the body of `event_type`: a `match` over the enum variants, or the
+/// type name for structs.
+fn event_type_method(input: &DeriveInput) -> proc_macro2::TokenStream {
+    match &input.data {
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let name = ident.to_string();
+                match &variant.fields {
+                    Fields::Unit => quote!(Self::#ident => #name),
+                    Fields::Unnamed(_) => quote!(Self::#ident(..) => #name),
+                    Fields::Named(_) => quote!(Self::#ident { .. } => #name),
+                }
+            });
+            quote! {
+                match self {
+                    #(#arms),*
+                }
+            }
+        }
+        _ => {
+            let name = input.ident.to_string();
+            quote!(#name)
+        }
+    }
+}
@@ -0,0 +1,46 @@
+#![allow(dead_code)]
+
+// The derives expand to `impl <Trait> for ..`, so the marker traits must be
+// in scope; a real consumer pulls both in from `fmodel`/`fmodel_derive` the
+// same way.
+use fmodel::{Command, Event, State};
+use fmodel_derive::{Command, Event, State};
+
+// Unconditionally serde-able (rather than `cfg_attr`-gated like the other
+// fixtures in this repo) since this crate has no "serde" feature of its own
+// to gate on — it must satisfy `fmodel`'s `Command`/`Event`/`State` bounds
+// whenever `fmodel`'s serde feature happens to be enabled.
+#[derive(serde::Serialize, serde::Deserialize, State)]
+struct Counter {
+    _value: u32,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Command)]
+enum CounterCmd {
+    Increment(u32),
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Event)]
+enum CounterEvt {
+    Incremented(u32),
+    Reset,
+    Renamed { _name: u32 },
+}
+
+fn assert_command<C: Command>() {}
+fn assert_event<E: Event>() {}
+fn assert_state<S: State>() {}
+
+#[test]
+fn marker_traits_are_implemented() {
+    assert_command::<CounterCmd>();
+    assert_event::<CounterEvt>();
+    assert_state::<Counter>();
+}
+
+#[test]
+fn event_type_returns_variant_name() {
+    assert_eq!(CounterEvt::Incremented(1).event_type(), "Incremented");
+    assert_eq!(CounterEvt::Reset.event_type(), "Reset");
+    assert_eq!(CounterEvt::Renamed { _name: 0 }.event_type(), "Renamed");
+}
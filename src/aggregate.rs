@@ -0,0 +1,328 @@
+use crate::decider::{Command, DeciderError, Event, IDecider, State};
+
+/// Persistence boundary for an event-sourced aggregate.
+///
+/// # Types
+///
+///   * `C` - Command type (used to locate the relevant stream)
+///   * `E` - Event type
+pub(crate) trait EventRepository<C, E>
+    where C: Command,
+          E: Event {
+    fn fetch_events(&self, command: &C) -> Vec<E>;
+    fn save(&self, events: &[E]) -> Vec<E>;
+}
+
+/// Persistence boundary for a state-stored aggregate.
+///
+/// # Types
+///
+///   * `C` - Command type (used to locate the stored state)
+///   * `S` - State type
+pub(crate) trait StateRepository<C, S>
+    where C: Command,
+          S: State {
+    fn fetch_state(&self, command: &C) -> S;
+    fn save(&self, state: &S) -> S;
+}
+
+/// Event-sourced aggregate: the effectful boundary around a pure `Decider`.
+///
+/// On `handle` it fetches the prior events, folds them through
+/// `Decider::evolve` to reconstruct the current state, then `decide`s the new
+/// events. Following the ECS deferred-command pattern the computed events are
+/// buffered in an internal `Vec` — several commands can be applied in-memory
+/// (each seeing the events scheduled by the previous one) and flushed to the
+/// repository in a single batch via `commit`.
+#[derive(Debug)]
+pub(crate) struct EventSourcedAggregate<C, S, E, D, R> {
+    decider: D,
+    repository: R,
+    buffer: Vec<E>,
+    _marker: core::marker::PhantomData<(C, S)>,
+}
+
+impl<C, S, E, D, R> EventSourcedAggregate<C, S, E, D, R>
+    where C: Command,
+          S: State + Clone,
+          E: Event + Clone,
+          D: IDecider<C, S, S, E, E>,
+          R: EventRepository<C, E>
+{
+    pub(crate) fn new(decider: D, repository: R) -> Self {
+        EventSourcedAggregate {
+            decider,
+            repository,
+            buffer: Vec::new(),
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    pub(crate) fn handle(&mut self, command: C) -> Result<Vec<E>, DeciderError> {
+        let mut state = self.decider.initial_state().clone();
+        for event in self.repository.fetch_events(&command) {
+            state = self.decider.evolve(state, event);
+        }
+        for event in &self.buffer {
+            state = self.decider.evolve(state, event.clone());
+        }
+
+        self.decider.validate(&command, &state)?;
+
+        let events = self.decider.decide(command, state);
+        self.buffer.extend(events.iter().cloned());
+        Ok(events)
+    }
+
+    pub(crate) fn commit(&mut self) -> Vec<E> {
+        let saved = self.repository.save(&self.buffer);
+        self.buffer.clear();
+        saved
+    }
+}
+
+/// State-stored aggregate: loads and saves the folded state directly.
+///
+/// On `handle` it loads the current state, `decide`s the new events, folds them
+/// back onto the state through `Decider::evolve`, and saves the resulting state.
+#[derive(Debug)]
+pub(crate) struct StateStoredAggregate<C, S, E, D, R> {
+    decider: D,
+    repository: R,
+    _marker: core::marker::PhantomData<(C, S, E)>,
+}
+
+impl<C, S, E, D, R> StateStoredAggregate<C, S, E, D, R>
+    where C: Command,
+          S: State + Clone,
+          E: Event,
+          D: IDecider<C, S, S, E, E>,
+          R: StateRepository<C, S>
+{
+    pub(crate) fn new(decider: D, repository: R) -> Self {
+        StateStoredAggregate {
+            decider,
+            repository,
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    pub(crate) fn handle(&self, command: C) -> Result<S, DeciderError> {
+        let mut state = self.repository.fetch_state(&command);
+        self.decider.validate(&command, &state)?;
+        for event in self.decider.decide(command, state.clone()) {
+            state = self.decider.evolve(state, event);
+        }
+        Ok(self.repository.save(&state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decider::Decider;
+    use core::cell::RefCell;
+
+    #[derive(Debug)]
+    struct Number {
+        decide_fn: fn(NumberCmd, NumberState) -> Vec<NumberEvt>,
+        evolve_fn: fn(NumberState, NumberEvt) -> NumberState,
+        initial_state: NumberState,
+    }
+
+    #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct NumberState {
+        value: u32,
+    }
+
+    impl NumberState {
+        fn new(num: u32) -> Self {
+            NumberState { value: num }
+        }
+    }
+
+    impl State for NumberState {}
+
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    enum NumberCmd {
+        AddNumber(u32),
+    }
+
+    impl Command for NumberCmd {}
+
+    #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    enum NumberEvt {
+        NumberAdded(u32),
+    }
+
+    impl Event for NumberEvt {}
+
+    impl IDecider<NumberCmd, NumberState, NumberState, NumberEvt, NumberEvt> for Number {
+        fn decide(&self, command: NumberCmd, state: NumberState) -> Vec<NumberEvt> {
+            (self.decide_fn)(command, state)
+        }
+
+        fn evolve(&self, state: NumberState, event: NumberEvt) -> NumberState {
+            (self.evolve_fn)(state, event)
+        }
+
+        fn initial_state(&self) -> &NumberState {
+            &self.initial_state
+        }
+    }
+
+    impl Decider<NumberCmd, NumberState, NumberEvt> for Number {
+        fn new(
+            decide: fn(command: NumberCmd, state: NumberState) -> Vec<NumberEvt>,
+            evolve: fn(state: NumberState, event: NumberEvt) -> NumberState,
+            initial_state: NumberState,
+        ) -> Self {
+            Number {
+                decide_fn: decide,
+                evolve_fn: evolve,
+                initial_state,
+            }
+        }
+    }
+
+    fn decide(command: NumberCmd, _state: NumberState) -> Vec<NumberEvt> {
+        match command {
+            NumberCmd::AddNumber(num) => vec![NumberEvt::NumberAdded(num)],
+        }
+    }
+
+    fn evolve(state: NumberState, event: NumberEvt) -> NumberState {
+        match event {
+            NumberEvt::NumberAdded(num) => NumberState::new(state.value + num),
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct InMemoryEventStore {
+        events: RefCell<Vec<NumberEvt>>,
+    }
+
+    impl EventRepository<NumberCmd, NumberEvt> for InMemoryEventStore {
+        fn fetch_events(&self, _command: &NumberCmd) -> Vec<NumberEvt> {
+            self.events.borrow().clone()
+        }
+
+        fn save(&self, events: &[NumberEvt]) -> Vec<NumberEvt> {
+            self.events.borrow_mut().extend_from_slice(events);
+            events.to_vec()
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct InMemoryStateStore {
+        state: RefCell<NumberState>,
+    }
+
+    impl StateRepository<NumberCmd, NumberState> for InMemoryStateStore {
+        fn fetch_state(&self, _command: &NumberCmd) -> NumberState {
+            *self.state.borrow()
+        }
+
+        fn save(&self, state: &NumberState) -> NumberState {
+            *self.state.borrow_mut() = *state;
+            *state
+        }
+    }
+
+    impl Default for NumberState {
+        fn default() -> Self {
+            NumberState::new(0)
+        }
+    }
+
+    #[test]
+    fn event_sourced_buffers_until_commit() {
+        let mut aggregate = EventSourcedAggregate::new(
+            Number::new(decide, evolve, NumberState::new(0)),
+            InMemoryEventStore::default(),
+        );
+
+        aggregate.handle(NumberCmd::AddNumber(1)).unwrap();
+        aggregate.handle(NumberCmd::AddNumber(2)).unwrap();
+
+        // Nothing persisted before commit.
+        assert!(aggregate.repository.events.borrow().is_empty());
+
+        let saved = aggregate.commit();
+        assert_eq!(
+            saved,
+            vec![NumberEvt::NumberAdded(1), NumberEvt::NumberAdded(2)]
+        );
+        assert!(aggregate.buffer.is_empty());
+    }
+
+    #[test]
+    fn state_stored_saves_folded_state() {
+        let store = InMemoryStateStore::default();
+        let aggregate = StateStoredAggregate::new(Number::new(decide, evolve, NumberState::new(0)), store);
+
+        let state = aggregate.handle(NumberCmd::AddNumber(5)).unwrap();
+
+        assert_eq!(state, NumberState::new(5));
+        assert_eq!(*aggregate.repository.state.borrow(), NumberState::new(5));
+    }
+
+    #[derive(Debug)]
+    struct GuardedNumber {
+        inner: Number,
+    }
+
+    impl IDecider<NumberCmd, NumberState, NumberState, NumberEvt, NumberEvt> for GuardedNumber {
+        fn decide(&self, command: NumberCmd, state: NumberState) -> Vec<NumberEvt> {
+            self.inner.decide(command, state)
+        }
+
+        fn evolve(&self, state: NumberState, event: NumberEvt) -> NumberState {
+            self.inner.evolve(state, event)
+        }
+
+        fn initial_state(&self) -> &NumberState {
+            self.inner.initial_state()
+        }
+
+        fn validate(&self, _command: &NumberCmd, state: &NumberState) -> Result<(), DeciderError> {
+            if state.value == 0 {
+                Err(DeciderError::ValidationFailed(
+                    "cannot add before a value exists".to_string(),
+                ))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    impl Decider<NumberCmd, NumberState, NumberEvt> for GuardedNumber {
+        fn new(
+            decide: fn(command: NumberCmd, state: NumberState) -> Vec<NumberEvt>,
+            evolve: fn(state: NumberState, event: NumberEvt) -> NumberState,
+            initial_state: NumberState,
+        ) -> Self {
+            GuardedNumber {
+                inner: Number::new(decide, evolve, initial_state),
+            }
+        }
+    }
+
+    #[test]
+    fn validate_rejects_before_decide() {
+        let store = InMemoryStateStore::default();
+        let aggregate =
+            StateStoredAggregate::new(GuardedNumber::new(decide, evolve, NumberState::new(0)), store);
+
+        let result = aggregate.handle(NumberCmd::AddNumber(5));
+
+        assert_eq!(
+            result,
+            Err(DeciderError::ValidationFailed(
+                "cannot add before a value exists".to_string()
+            ))
+        );
+    }
+}
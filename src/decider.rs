@@ -1,8 +1,39 @@
-trait Command {}
+#[cfg(feature = "serde")]
+pub trait Command: serde::Serialize + for<'de> serde::Deserialize<'de> {}
+#[cfg(not(feature = "serde"))]
+pub trait Command {}
 
-trait Event {}
+#[cfg(feature = "serde")]
+pub trait Event: serde::Serialize + for<'de> serde::Deserialize<'de> {}
+#[cfg(not(feature = "serde"))]
+pub trait Event {}
 
-trait State {}
+#[cfg(feature = "serde")]
+pub trait State: serde::Serialize + for<'de> serde::Deserialize<'de> {}
+#[cfg(not(feature = "serde"))]
+pub trait State {}
+
+/// Error raised at the effectful boundary before a command is decided.
+///
+/// Returning this from [`IDecider::validate`] lets domain invariants (e.g.
+/// "cannot multiply before a value exists") be enforced without panicking.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(crate) enum DeciderError {
+    /// A domain invariant rejected the command in the current state.
+    ValidationFailed(String),
+}
+
+impl std::fmt::Display for DeciderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeciderError::ValidationFailed(reason) => {
+                write!(f, "command validation failed: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DeciderError {}
 
 /// IDecider trait
 ///
@@ -13,7 +44,7 @@ trait State {}
 ///   * `So` - Output state type
 ///   * `Ei` - Initial event type
 ///   * `Eo` - Output event type
-trait IDecider<C, Si, So, Ei, Eo>
+pub(crate) trait IDecider<C, Si, So, Ei, Eo>
     where C: Command,
           Si: State,
           So: State,
@@ -23,6 +54,14 @@ trait IDecider<C, Si, So, Ei, Eo>
     fn decide(&self, command: C, state: Si) -> Vec<Eo>;
     fn evolve(&self, state: Si, event: Ei) -> So;
     fn initial_state(&self) -> &So;
+
+    /// Validate a command against the current state before it is decided.
+    ///
+    /// The default accepts every command; override to enforce domain
+    /// invariants, returning a [`DeciderError`] instead of panicking.
+    fn validate(&self, _command: &C, _state: &Si) -> Result<(), DeciderError> {
+        Ok(())
+    }
 }
 
 /// Decider trait
@@ -32,7 +71,7 @@ trait IDecider<C, Si, So, Ei, Eo>
 ///   * `C` - Command type
 ///   * `S` - State type
 ///   * `E` - Event type
-trait Decider<C, S, E>: IDecider<C, S, S, E, E>
+pub(crate) trait Decider<C, S, E>: IDecider<C, S, S, E, E>
     where C: Command,
           S: State,
           E: Event {
@@ -48,9 +87,107 @@ trait Identity<T> {
     fn identity(&self) -> T;
 }
 
+/// A value that is either a `Left` or a `Right`.
+///
+/// Used to tag commands and events so that two deciders working over disjoint
+/// command/event alphabets can be combined into one without the halves
+/// interfering.
+#[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L: Command, R: Command> Command for Either<L, R> {}
+impl<L: Event, R: Event> Event for Either<L, R> {}
+impl<L: State, R: State> State for Either<L, R> {}
+
+impl<A: State, B: State> State for (A, B) {}
+
+/// A `Decider` assembled from two smaller deciders.
+///
+/// Produced by [`combine`]; routes `Either`-tagged commands/events to the
+/// matching half and keeps the two state halves side by side in a tuple.
+#[derive(Debug)]
+pub(crate) struct Combined<D1, D2, S1, S2> {
+    left: D1,
+    right: D2,
+    initial_state: (S1, S2),
+}
+
+impl<D1, D2, C1, S1, E1, C2, S2, E2>
+    IDecider<Either<C1, C2>, (S1, S2), (S1, S2), Either<E1, E2>, Either<E1, E2>>
+    for Combined<D1, D2, S1, S2>
+    where D1: IDecider<C1, S1, S1, E1, E1>,
+          D2: IDecider<C2, S2, S2, E2, E2>,
+          C1: Command,
+          C2: Command,
+          S1: State,
+          S2: State,
+          E1: Event,
+          E2: Event
+{
+    fn decide(&self, command: Either<C1, C2>, state: (S1, S2)) -> Vec<Either<E1, E2>> {
+        match command {
+            Either::Left(c) => self
+                .left
+                .decide(c, state.0)
+                .into_iter()
+                .map(Either::Left)
+                .collect(),
+            Either::Right(c) => self
+                .right
+                .decide(c, state.1)
+                .into_iter()
+                .map(Either::Right)
+                .collect(),
+        }
+    }
+
+    fn evolve(&self, state: (S1, S2), event: Either<E1, E2>) -> (S1, S2) {
+        match event {
+            Either::Left(e) => (self.left.evolve(state.0, e), state.1),
+            Either::Right(e) => (state.0, self.right.evolve(state.1, e)),
+        }
+    }
+
+    fn initial_state(&self) -> &(S1, S2) {
+        &self.initial_state
+    }
+
+    fn validate(&self, command: &Either<C1, C2>, state: &(S1, S2)) -> Result<(), DeciderError> {
+        match command {
+            Either::Left(c) => self.left.validate(c, &state.0),
+            Either::Right(c) => self.right.validate(c, &state.1),
+        }
+    }
+}
+
+/// Combine two deciders into one over the `Either` of their command/event
+/// alphabets and the tuple of their states.
+pub(crate) fn combine<D1, D2, C1, S1, E1, C2, S2, E2>(left: D1, right: D2) -> Combined<D1, D2, S1, S2>
+    where D1: Decider<C1, S1, E1>,
+          D2: Decider<C2, S2, E2>,
+          C1: Command,
+          C2: Command,
+          S1: State + Clone,
+          S2: State + Clone,
+          E1: Event,
+          E2: Event
+{
+    let initial_state = (left.initial_state().clone(), right.initial_state().clone());
+    Combined {
+        left,
+        right,
+        initial_state,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use fmodel_derive::{Command as DeriveCommand, Event as DeriveEvent, State as DeriveState};
 
     #[derive(Debug)]
     struct Number {
@@ -59,7 +196,10 @@ mod tests {
         initial_state: NumberState,
     }
 
-    #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+    // `State`/`Command`/`Event` derived below instead of hand-written, proving
+    // the fmodel-derive macros work against the real marker traits.
+    #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq, DeriveState)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     struct NumberState {
         value: u32,
     }
@@ -70,8 +210,9 @@ mod tests {
         }
     }
 
-    impl State for NumberState {}
-
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[allow(clippy::enum_variant_names)]
+    #[derive(DeriveCommand)]
     enum NumberCmd {
         AddOddNumber(u32),
         MultiplyOddNumber(u32),
@@ -79,9 +220,8 @@ mod tests {
         MultiplyEvenNumber(u32),
     }
 
-    impl Command for NumberCmd {}
-
-    #[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
+    #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, DeriveEvent)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     enum NumberEvt {
         OddNumberAdded(u32),
         OddNumberMultiplied(u32),
@@ -89,16 +229,14 @@ mod tests {
         EvenNumberMultiplied(u32),
     }
 
-    impl Event for NumberEvt {}
-
     impl IDecider<NumberCmd, NumberState, NumberState, NumberEvt, NumberEvt> for Number
     {
         fn decide(&self, command: NumberCmd, state: NumberState) -> Vec<NumberEvt> {
-            (&self.decide_fn)(command, state)
+            (self.decide_fn)(command, state)
         }
 
         fn evolve(&self, state: NumberState, event: NumberEvt) -> NumberState {
-            (&self.evolve_fn)(state, event)
+            (self.evolve_fn)(state, event)
         }
 
         fn initial_state(&self) -> &NumberState {
@@ -142,7 +280,7 @@ mod tests {
     fn initial_state() {
         let f = Number::new(decide, evolve, NumberState::new(0));
 
-        let state = f.initial_state().clone();
+        let state = *f.initial_state();
         let expected = NumberState::new(0);
 
         assert_eq!(state, expected);
@@ -167,6 +305,104 @@ mod tests {
 
         assert_eq!(events, expected)
     }
+
+    #[test]
+    fn combine_routes_to_left() {
+        let f = combine(
+            Number::new(decide, evolve, NumberState::new(0)),
+            Number::new(decide, evolve, NumberState::new(0)),
+        );
+
+        let command = Either::Left(NumberCmd::AddOddNumber(1));
+        let events = f.decide(command, (NumberState::new(0), NumberState::new(0)));
+
+        assert_eq!(events, vec![Either::Left(NumberEvt::OddNumberAdded(1))]);
+    }
+
+    #[test]
+    fn combine_evolves_matching_half() {
+        let f = combine(
+            Number::new(decide, evolve, NumberState::new(0)),
+            Number::new(decide, evolve, NumberState::new(0)),
+        );
+
+        let state = (NumberState::new(1), NumberState::new(2));
+        let new_state = f.evolve(state, Either::Right(NumberEvt::EvenNumberAdded(3)));
+
+        assert_eq!(new_state, (NumberState::new(1), NumberState::new(5)));
+    }
+
+    #[test]
+    fn combine_initial_state() {
+        let f = combine(
+            Number::new(decide, evolve, NumberState::new(1)),
+            Number::new(decide, evolve, NumberState::new(2)),
+        );
+
+        assert_eq!(*f.initial_state(), (NumberState::new(1), NumberState::new(2)));
+    }
+
+    #[derive(Debug)]
+    struct GuardedNumber {
+        inner: Number,
+    }
+
+    impl IDecider<NumberCmd, NumberState, NumberState, NumberEvt, NumberEvt> for GuardedNumber {
+        fn decide(&self, command: NumberCmd, state: NumberState) -> Vec<NumberEvt> {
+            self.inner.decide(command, state)
+        }
+
+        fn evolve(&self, state: NumberState, event: NumberEvt) -> NumberState {
+            self.inner.evolve(state, event)
+        }
+
+        fn initial_state(&self) -> &NumberState {
+            self.inner.initial_state()
+        }
+
+        fn validate(&self, _command: &NumberCmd, state: &NumberState) -> Result<(), DeciderError> {
+            if state.value == 0 {
+                Err(DeciderError::ValidationFailed(
+                    "cannot add before a value exists".to_string(),
+                ))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    impl Decider<NumberCmd, NumberState, NumberEvt> for GuardedNumber {
+        fn new(
+            decide: fn(command: NumberCmd, state: NumberState) -> Vec<NumberEvt>,
+            evolve: fn(state: NumberState, event: NumberEvt) -> NumberState,
+            initial_state: NumberState,
+        ) -> Self {
+            GuardedNumber {
+                inner: Number::new(decide, evolve, initial_state),
+            }
+        }
+    }
+
+    #[test]
+    fn combine_validate_routes_to_matching_half() {
+        let f = combine(
+            GuardedNumber::new(decide, evolve, NumberState::new(0)),
+            Number::new(decide, evolve, NumberState::new(0)),
+        );
+
+        let state = (NumberState::new(0), NumberState::new(0));
+
+        let left_result = f.validate(&Either::Left(NumberCmd::AddOddNumber(1)), &state);
+        assert_eq!(
+            left_result,
+            Err(DeciderError::ValidationFailed(
+                "cannot add before a value exists".to_string()
+            ))
+        );
+
+        let right_result = f.validate(&Either::Right(NumberCmd::AddEvenNumber(1)), &state);
+        assert_eq!(right_result, Ok(()));
+    }
 }
 
 
@@ -0,0 +1,11 @@
+// The decider/saga/aggregate/view items are currently exercised only by each
+// module's own unit tests (no `pub` surface or examples consume them yet),
+// which would otherwise make every one of them look dead in a non-test build.
+#![allow(dead_code)]
+
+mod aggregate;
+mod decider;
+mod saga;
+mod view;
+
+pub use decider::{Command, Event, State};
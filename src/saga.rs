@@ -0,0 +1,176 @@
+use crate::decider::{Command, Event, IDecider, State};
+
+/// Saga trait
+///
+/// A `Saga` is the process-manager piece that closes the loop between
+/// aggregates: it reacts to an action-result `AR` (typically an event produced
+/// by one `Decider::decide`) and emits follow-up actions `A` (commands) to be
+/// fed into another `Decider`. Like `Decider`/`View` it is built from a single
+/// pure function.
+///
+/// # Types
+///
+///   * `AR` - Action-result type (reacted upon)
+///   * `A` - Action type (emitted)
+pub(crate) trait Saga<AR, A>
+    where AR: Event,
+          A: Command {
+    fn new(react: fn(action_result: AR) -> Vec<A>) -> Self;
+
+    fn react(&self, action_result: AR) -> Vec<A>;
+}
+
+/// Wire a `Decider` to a `Saga`.
+///
+/// Running the decider's `decide` produces events; each event is handed to the
+/// saga's `react`, scheduling the reactive commands. The events are returned
+/// alongside the follow-up actions so the caller can both persist the former
+/// and dispatch the latter into another decider.
+pub(crate) fn combine<D, Sg, C, S, E, A>(
+    decider: &D,
+    saga: &Sg,
+    command: C,
+    state: S,
+) -> (Vec<E>, Vec<A>)
+    where D: IDecider<C, S, S, E, E>,
+          Sg: Saga<E, A>,
+          C: Command,
+          S: State,
+          E: Event + Clone,
+          A: Command
+{
+    let events = decider.decide(command, state);
+    let actions = events
+        .iter()
+        .cloned()
+        .flat_map(|event| saga.react(event))
+        .collect();
+    (events, actions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decider::{Decider, IDecider};
+
+    #[derive(Debug)]
+    struct NumberSaga {
+        react_fn: fn(NumberEvt) -> Vec<NumberCmd>,
+    }
+
+    #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct NumberState {
+        value: u32,
+    }
+
+    impl NumberState {
+        fn new(num: u32) -> Self {
+            NumberState { value: num }
+        }
+    }
+
+    impl State for NumberState {}
+
+    #[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    enum NumberCmd {
+        AddNumber(u32),
+    }
+
+    impl Command for NumberCmd {}
+
+    #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    enum NumberEvt {
+        NumberAdded(u32),
+    }
+
+    impl Event for NumberEvt {}
+
+    impl Saga<NumberEvt, NumberCmd> for NumberSaga {
+        fn new(react: fn(NumberEvt) -> Vec<NumberCmd>) -> Self {
+            NumberSaga { react_fn: react }
+        }
+
+        fn react(&self, action_result: NumberEvt) -> Vec<NumberCmd> {
+            (self.react_fn)(action_result)
+        }
+    }
+
+    // A minimal decider so `combine` can be exercised against a real write side.
+    #[derive(Debug)]
+    struct Number {
+        decide_fn: fn(NumberCmd, NumberState) -> Vec<NumberEvt>,
+        evolve_fn: fn(NumberState, NumberEvt) -> NumberState,
+        initial_state: NumberState,
+    }
+
+    impl IDecider<NumberCmd, NumberState, NumberState, NumberEvt, NumberEvt> for Number {
+        fn decide(&self, command: NumberCmd, state: NumberState) -> Vec<NumberEvt> {
+            (self.decide_fn)(command, state)
+        }
+
+        fn evolve(&self, state: NumberState, event: NumberEvt) -> NumberState {
+            (self.evolve_fn)(state, event)
+        }
+
+        fn initial_state(&self) -> &NumberState {
+            &self.initial_state
+        }
+    }
+
+    impl Decider<NumberCmd, NumberState, NumberEvt> for Number {
+        fn new(
+            decide: fn(command: NumberCmd, state: NumberState) -> Vec<NumberEvt>,
+            evolve: fn(state: NumberState, event: NumberEvt) -> NumberState,
+            initial_state: NumberState,
+        ) -> Self {
+            Number {
+                decide_fn: decide,
+                evolve_fn: evolve,
+                initial_state,
+            }
+        }
+    }
+
+    fn decide(command: NumberCmd, _state: NumberState) -> Vec<NumberEvt> {
+        match command {
+            NumberCmd::AddNumber(num) => vec![NumberEvt::NumberAdded(num)],
+        }
+    }
+
+    fn evolve(state: NumberState, event: NumberEvt) -> NumberState {
+        match event {
+            NumberEvt::NumberAdded(num) => NumberState::new(state.value + num),
+        }
+    }
+
+    // Each added number schedules a follow-up command adding its successor.
+    fn react(action_result: NumberEvt) -> Vec<NumberCmd> {
+        match action_result {
+            NumberEvt::NumberAdded(num) => vec![NumberCmd::AddNumber(num + 1)],
+        }
+    }
+
+    #[test]
+    fn react_fn() {
+        let s = NumberSaga::new(react);
+
+        let actions = s.react(NumberEvt::NumberAdded(1));
+        let expected = vec![NumberCmd::AddNumber(2)];
+
+        assert_eq!(actions, expected);
+    }
+
+    #[test]
+    fn combine_fn() {
+        let d = Number::new(decide, evolve, NumberState::new(0));
+        let s = NumberSaga::new(react);
+
+        let (events, actions) = combine(&d, &s, NumberCmd::AddNumber(1), NumberState::new(0));
+
+        assert_eq!(events, vec![NumberEvt::NumberAdded(1)]);
+        assert_eq!(actions, vec![NumberCmd::AddNumber(2)]);
+    }
+}
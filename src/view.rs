@@ -0,0 +1,129 @@
+use crate::decider::{Event, State};
+
+/// View trait
+///
+/// A `View` captures the read side of the model: a pure projection that folds
+/// an event stream up into a current materialized `State`. Unlike a `Decider`
+/// it carries no command/`decide` machinery, so consumers can build query-side
+/// projections while reusing the same `State`/`Event` marker traits.
+///
+/// # Types
+///
+///   * `S` - State type
+///   * `E` - Event type
+pub(crate) trait View<S, E>
+    where S: State,
+          E: Event {
+    fn new(
+        evolve: fn(state: S, event: E) -> S,
+        initial_state: S,
+    ) -> Self;
+
+    fn evolve(&self, state: S, event: E) -> S;
+    fn initial_state(&self) -> &S;
+
+    /// Fold an event stream into a final state, starting from `initial_state`.
+    fn fold(&self, events: impl IntoIterator<Item = E>) -> S
+        where S: Clone
+    {
+        let mut state = self.initial_state().clone();
+        for event in events {
+            state = self.evolve(state, event);
+        }
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct NumberView {
+        evolve_fn: fn(NumberState, NumberEvt) -> NumberState,
+        initial_state: NumberState,
+    }
+
+    #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    struct NumberState {
+        value: u32,
+    }
+
+    impl NumberState {
+        fn new(num: u32) -> Self {
+            NumberState { value: num }
+        }
+    }
+
+    impl State for NumberState {}
+
+    #[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    enum NumberEvt {
+        NumberAdded(u32),
+        NumberMultiplied(u32),
+    }
+
+    impl Event for NumberEvt {}
+
+    impl View<NumberState, NumberEvt> for NumberView {
+        fn new(
+            evolve: fn(state: NumberState, event: NumberEvt) -> NumberState,
+            initial_state: NumberState,
+        ) -> Self {
+            NumberView {
+                evolve_fn: evolve,
+                initial_state,
+            }
+        }
+
+        fn evolve(&self, state: NumberState, event: NumberEvt) -> NumberState {
+            (self.evolve_fn)(state, event)
+        }
+
+        fn initial_state(&self) -> &NumberState {
+            &self.initial_state
+        }
+    }
+
+    fn evolve(state: NumberState, event: NumberEvt) -> NumberState {
+        match event {
+            NumberEvt::NumberAdded(num) => NumberState::new(state.value + num),
+            NumberEvt::NumberMultiplied(num) => NumberState::new(state.value * num),
+        }
+    }
+
+    #[test]
+    fn initial_state() {
+        let v = NumberView::new(evolve, NumberState::new(0));
+
+        let state = *v.initial_state();
+        let expected = NumberState::new(0);
+
+        assert_eq!(state, expected);
+    }
+
+    #[test]
+    fn evolve_fn() {
+        let v = NumberView::new(evolve, NumberState::new(0));
+
+        let expected_state = NumberState::new(4);
+        let new_state = v.evolve(NumberState::new(2), NumberEvt::NumberAdded(2));
+
+        assert_eq!(new_state, expected_state);
+    }
+
+    #[test]
+    fn fold_fn() {
+        let v = NumberView::new(evolve, NumberState::new(1));
+
+        let state = v.fold(vec![
+            NumberEvt::NumberAdded(2),
+            NumberEvt::NumberMultiplied(3),
+        ]);
+        let expected = NumberState::new(9);
+
+        assert_eq!(state, expected);
+    }
+}